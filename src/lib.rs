@@ -13,8 +13,10 @@
 //! let d = m.powmod(5, 42);
 //! assert_eq!(d, 7);
 //! ```
-use core::ops::{Add, AddAssign, BitAnd, Mul, Neg, Rem, ShrAssign, Sub, SubAssign};
+extern crate alloc;
+use core::ops::{Add, AddAssign, BitAnd, Div, Mul, Neg, Rem, ShrAssign, Sub, SubAssign};
 pub mod montgomery;
+pub mod prime;
 
 fn reduce<T>(mut a: T, modulo: &T) -> T
 where
@@ -151,3 +153,252 @@ where
     }
     y
 }
+
+pub(crate) fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: PartialEq + From<u8>,
+    for<'x> &'x T: Rem<Output = T>,
+{
+    let zero = T::from(0);
+    while b != zero {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// $`a^{-1} \bmod n`$, via the extended Euclidean algorithm
+///
+/// Input: $`0 \leq a < \text{modulo}`$
+/// Output: `Some(x)` with $`0 \leq x < \text{modulo}`$ and $`ax \equiv 1`$,
+/// or `None` when $`\gcd(a, \text{modulo}) \neq 1`$.
+/// ```
+/// use modulo_n_tools::inv_mod;
+/// assert_eq!(inv_mod(3, 5), Some(2));
+/// assert_eq!(inv_mod(2, 4), None);
+/// assert_eq!(inv_mod(3u64, 5u64), Some(2));
+/// ```
+pub fn inv_mod<T>(a: T, modulo: T) -> Option<T>
+where
+    T: Clone + Ord + From<u8>,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let one = T::from(1);
+    let (mut old_r, mut r) = (modulo.clone(), a);
+    // (negative, magnitude) pairs: `s` only ever needs to represent a
+    // magnitude of at most `modulo`, but can swing positive/negative each step
+    let (mut old_s_neg, mut old_s) = (false, zero.clone());
+    let (mut s_neg, mut s) = (false, one.clone());
+    while r != zero {
+        let q = &old_r / &r;
+        let new_r = &old_r - &(&q * &r);
+        old_r = r;
+        r = new_r;
+
+        // new_s = old_s - q * s, done on magnitudes with the sign worked out by hand
+        let qs = &q * &s;
+        let (new_s_neg, new_s) = if old_s_neg == s_neg {
+            if old_s >= qs {
+                (old_s_neg, &old_s - &qs)
+            } else {
+                (!old_s_neg, &qs - &old_s)
+            }
+        } else {
+            (old_s_neg, &old_s + &qs)
+        };
+        old_s_neg = s_neg;
+        old_s = s;
+        s_neg = new_s_neg;
+        s = new_s;
+    }
+    if old_r != one {
+        return None;
+    }
+    let t = &old_s % &modulo;
+    Some(if old_s_neg && t != zero {
+        &modulo - &t
+    } else {
+        t
+    })
+}
+
+/// $`a^{-1} \bmod n`$ for prime $`n`$, via Fermat's little theorem
+///
+/// Does not detect a non-prime `modulo`: the result is simply wrong in that case.
+///
+/// Input: $`0 \leq a < \text{modulo}`$, `modulo` prime
+/// Output: $`0 \leq x < \text{modulo}`$ with $`ax \equiv 1`$
+/// ```
+/// use modulo_n_tools::inv_mod_prime;
+/// assert_eq!(inv_mod_prime(3, &5), 2);
+/// ```
+pub fn inv_mod_prime<T>(a: T, modulo: &T) -> T
+where
+    T: Clone + Ord + ShrAssign<u8> + From<u8>,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Rem<Output = T> + BitAnd<Output = T>,
+{
+    let two = T::from(2);
+    let exponent = modulo - &two;
+    pow_mod(a, exponent, modulo)
+}
+
+/// $`a / b \bmod n`$, i.e. $`a \cdot b^{-1} \bmod n`$
+///
+/// Input: $`0 \leq a, b < \text{modulo}`$
+/// Output: `Some(x)` with $`0 \leq x < \text{modulo}`$, or `None` when `b`
+/// has no inverse modulo `modulo`.
+/// ```
+/// use modulo_n_tools::div_mod;
+/// assert_eq!(div_mod(&4, &3, &5), Some(3));
+/// assert_eq!(div_mod(&4u64, &3u64, &5u64), Some(3));
+/// ```
+pub fn div_mod<T>(a: &T, b: &T, modulo: &T) -> Option<T>
+where
+    T: Clone + Ord + From<u8>,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let b_inv = inv_mod(b.clone(), modulo.clone())?;
+    Some(mul_mod(a, &b_inv, modulo))
+}
+
+/// Chinese Remainder Theorem: solve $`x \equiv r_1 \pmod{m_1}`$ and
+/// $`x \equiv r_2 \pmod{m_2}`$ for possibly non-coprime $`m_1, m_2`$
+///
+/// Input: $`0 \leq r_1 < m_1`$, $`0 \leq r_2 < m_2`$
+/// Output: `Some((r, lcm))` with $`0 \leq r < \text{lcm} = \mathrm{lcm}(m_1, m_2)`$,
+/// or `None` if the two congruences are inconsistent.
+/// ```
+/// use modulo_n_tools::crt;
+/// assert_eq!(crt(&2, &3, &3, &5), Some((8, 15)));
+/// assert_eq!(crt(&0, &4, &1, &6), None);
+/// assert_eq!(crt(&3u64, &5u64, &1u64, &4u64), Some((13, 20)));
+/// ```
+pub fn crt<T>(r1: &T, m1: &T, r2: &T, m2: &T) -> Option<(T, T)>
+where
+    T: Clone + Ord + From<u8>,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let g = gcd(m1.clone(), m2.clone());
+    let (diff_neg, diff) = if r2 >= r1 {
+        (false, r2 - r1)
+    } else {
+        (true, r1 - r2)
+    };
+    if &diff % &g != zero {
+        return None;
+    }
+    let m1_g = m1 / &g;
+    let m2_g = m2 / &g;
+    let diff_g = &diff / &g;
+    let k = inv_mod(m1_g.clone(), m2_g.clone())?;
+    let k = &k * &diff_g;
+    let k = {
+        let t = &k % &m2_g;
+        if diff_neg && t != zero {
+            &m2_g - &t
+        } else {
+            t
+        }
+    };
+    let lcm = &m1_g * m2;
+    // r1 and m1 * k are both non-negative, so no sign correction is needed here
+    let r = &(r1 + &(m1 * &k)) % &lcm;
+    Some((r, lcm))
+}
+
+/// Fold [`crt`] over a slice of congruences $`x \equiv r_i \pmod{m_i}`$,
+/// short-circuiting to `None` as soon as two of them are inconsistent.
+///
+/// ```
+/// use modulo_n_tools::crt_many;
+/// assert_eq!(crt_many(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+/// ```
+pub fn crt_many<T>(rs: &[T], ms: &[T]) -> Option<(T, T)>
+where
+    T: Clone + Ord + From<u8>,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    assert_eq!(rs.len(), ms.len());
+    let mut iter = rs.iter().zip(ms.iter());
+    let (r0, m0) = iter.next()?;
+    let mut acc_r = r0.clone();
+    let mut acc_m = m0.clone();
+    for (r, m) in iter {
+        let (nr, nm) = crt(&acc_r, &acc_m, r, m)?;
+        acc_r = nr;
+        acc_m = nm;
+    }
+    Some((acc_r, acc_m))
+}
+
+/// Invert every element of `xs` modulo `modulo`, in place, using Montgomery's
+/// batch inversion trick: `k` inversions become `1` inversion plus `3k`
+/// multiplications.
+///
+/// Input: every $`x_i`$ with $`0 \leq x_i < \text{modulo}`$
+/// Output: `Ok(())` with `xs` overwritten by the inverses, or `Err` with the
+/// indices of the zero entries (which would otherwise poison every later
+/// prefix product) if there are any, and otherwise the indices of every entry
+/// that has no inverse modulo `modulo`. `xs` is left unchanged on `Err`.
+/// ```
+/// use modulo_n_tools::batch_inv_mod;
+/// let mut xs = [2, 3, 4];
+/// assert_eq!(batch_inv_mod(&mut xs, &7), Ok(()));
+/// assert_eq!(xs, [4, 5, 2]);
+/// assert_eq!(batch_inv_mod(&mut [1, 0, 2], &7), Err(vec![1]));
+/// ```
+pub fn batch_inv_mod<T>(xs: &mut [T], modulo: &T) -> Result<(), alloc::vec::Vec<usize>>
+where
+    T: Clone + Ord + From<u8>,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let one = T::from(1);
+    if xs.is_empty() {
+        return Ok(());
+    }
+    let zero_positions: alloc::vec::Vec<usize> = xs
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| **x == zero)
+        .map(|(i, _)| i)
+        .collect();
+    if !zero_positions.is_empty() {
+        return Err(zero_positions);
+    }
+    let mut prefix = alloc::vec::Vec::with_capacity(xs.len());
+    let mut acc = xs[0].clone();
+    prefix.push(acc.clone());
+    for x in &xs[1..] {
+        acc = mul_mod(&acc, x, modulo);
+        prefix.push(acc.clone());
+    }
+    let mut running = match inv_mod(acc, modulo.clone()) {
+        Some(inv) => inv,
+        None => {
+            let bad = xs
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| gcd((*x).clone(), modulo.clone()) != one)
+                .map(|(i, _)| i)
+                .collect();
+            return Err(bad);
+        }
+    };
+    for i in (1..xs.len()).rev() {
+        let x_i = xs[i].clone();
+        xs[i] = mul_mod(&running, &prefix[i - 1], modulo);
+        running = mul_mod(&running, &x_i, modulo);
+    }
+    xs[0] = running;
+    Ok(())
+}
@@ -1,4 +1,8 @@
 use core::convert::TryFrom;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, Mul, MulAssign, Neg, Rem, Shl, ShlAssign, ShrAssign, Sub,
+    SubAssign,
+};
 
 /// Montgomery modular multiplication
 ///
@@ -246,3 +250,403 @@ where
         self.reduction(x)
     }
 }
+
+/// A residue held in Montgomery form for [`Montgomery64`]
+///
+/// Stays in Montgomery form across `+`, `-`, `*` and `-x`; only [`from_int`]
+/// and [`to_int`] cross in and out of ordinary form.
+///
+/// [`from_int`]: MontgomeryElement64::from_int
+/// [`to_int`]: MontgomeryElement64::to_int
+/// ```
+/// use modulo_n_tools::montgomery::{Montgomery64, MontgomeryElement64, MontgomeryOperation};
+/// let m = Montgomery64::new(97);
+/// let a = MontgomeryElement64::from_int(&m, 60);
+/// let b = MontgomeryElement64::from_int(&m, 70);
+/// assert_eq!((a + b).to_int(), 33);
+/// assert_eq!((a - b).to_int(), 87);
+/// assert_eq!((a * b).to_int(), 29);
+/// assert_eq!(a.pow(77u64).to_int(), 58);
+/// ```
+#[derive(Clone, Copy)]
+pub struct MontgomeryElement64<'a> {
+    ctx: &'a Montgomery64,
+    val: u64,
+}
+
+impl<'a> MontgomeryElement64<'a> {
+    /// $`x \mapsto xR \bmod N`$
+    pub fn from_int(ctx: &'a Montgomery64, x: u64) -> Self {
+        MontgomeryElement64 {
+            ctx,
+            val: ctx.convert(x),
+        }
+    }
+    /// $`xR \bmod N \mapsto x`$
+    pub fn to_int(&self) -> u64 {
+        self.ctx.reduction(u128::from(self.val))
+    }
+    /// $`x^p R \bmod N`$, staying in Montgomery form
+    pub fn pow<V>(&self, mut p: V) -> Self
+    where
+        V: Ord + ShrAssign<u8> + From<u8>,
+        for<'x> &'x V: BitAnd<Output = V>,
+    {
+        let c0 = V::from(0);
+        let c1 = V::from(1);
+        let mut x = *self;
+        let mut y = MontgomeryElement64::from_int(self.ctx, 1);
+        while p > c0 {
+            if &p & &c1 != c0 {
+                y *= x;
+            }
+            x = x * x;
+            p >>= 1;
+        }
+        y
+    }
+}
+
+impl<'a> Add for MontgomeryElement64<'a> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement64 operands must share the same modulus"
+        );
+        let sum = u128::from(self.val) + u128::from(rhs.val);
+        let n = u128::from(self.ctx.n);
+        let val = u64::try_from(if sum >= n { sum - n } else { sum }).unwrap();
+        MontgomeryElement64 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> Sub for MontgomeryElement64<'a> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement64 operands must share the same modulus"
+        );
+        let val = if self.val >= rhs.val {
+            self.val - rhs.val
+        } else {
+            let diff = u128::from(self.ctx.n) + u128::from(self.val) - u128::from(rhs.val);
+            u64::try_from(diff).unwrap()
+        };
+        MontgomeryElement64 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> Neg for MontgomeryElement64<'a> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let val = if self.val == 0 {
+            0
+        } else {
+            self.ctx.n - self.val
+        };
+        MontgomeryElement64 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> Mul for MontgomeryElement64<'a> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement64 operands must share the same modulus"
+        );
+        let val = self
+            .ctx
+            .reduction(u128::from(self.val) * u128::from(rhs.val));
+        MontgomeryElement64 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> MulAssign for MontgomeryElement64<'a> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// A residue held in Montgomery form for [`Montgomery32`]
+///
+/// See [`MontgomeryElement64`] for the rationale.
+/// ```
+/// use modulo_n_tools::montgomery::{Montgomery32, MontgomeryElement32, MontgomeryOperation};
+/// let m = Montgomery32::new(89);
+/// let a = MontgomeryElement32::from_int(&m, 40);
+/// let b = MontgomeryElement32::from_int(&m, 60);
+/// assert_eq!((a + b).to_int(), 11);
+/// assert_eq!((a - b).to_int(), 69);
+/// assert_eq!((a * b).to_int(), 86);
+/// assert_eq!(a.pow(57u64).to_int(), 68);
+/// ```
+#[derive(Clone, Copy)]
+pub struct MontgomeryElement32<'a> {
+    ctx: &'a Montgomery32,
+    val: u32,
+}
+
+impl<'a> MontgomeryElement32<'a> {
+    /// $`x \mapsto xR \bmod N`$
+    pub fn from_int(ctx: &'a Montgomery32, x: u32) -> Self {
+        MontgomeryElement32 {
+            ctx,
+            val: ctx.convert(x),
+        }
+    }
+    /// $`xR \bmod N \mapsto x`$
+    pub fn to_int(&self) -> u32 {
+        self.ctx.reduction(u64::from(self.val))
+    }
+    /// $`x^p R \bmod N`$, staying in Montgomery form
+    pub fn pow<V>(&self, mut p: V) -> Self
+    where
+        V: Ord + ShrAssign<u8> + From<u8>,
+        for<'x> &'x V: BitAnd<Output = V>,
+    {
+        let c0 = V::from(0);
+        let c1 = V::from(1);
+        let mut x = *self;
+        let mut y = MontgomeryElement32::from_int(self.ctx, 1);
+        while p > c0 {
+            if &p & &c1 != c0 {
+                y *= x;
+            }
+            x = x * x;
+            p >>= 1;
+        }
+        y
+    }
+}
+
+impl<'a> Add for MontgomeryElement32<'a> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement32 operands must share the same modulus"
+        );
+        let sum = u64::from(self.val) + u64::from(rhs.val);
+        let n = u64::from(self.ctx.n);
+        let val = u32::try_from(if sum >= n { sum - n } else { sum }).unwrap();
+        MontgomeryElement32 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> Sub for MontgomeryElement32<'a> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement32 operands must share the same modulus"
+        );
+        let val = if self.val >= rhs.val {
+            self.val - rhs.val
+        } else {
+            let diff = u64::from(self.ctx.n) + u64::from(self.val) - u64::from(rhs.val);
+            u32::try_from(diff).unwrap()
+        };
+        MontgomeryElement32 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> Neg for MontgomeryElement32<'a> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let val = if self.val == 0 {
+            0
+        } else {
+            self.ctx.n - self.val
+        };
+        MontgomeryElement32 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> Mul for MontgomeryElement32<'a> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement32 operands must share the same modulus"
+        );
+        let val = self.ctx.reduction(u64::from(self.val) * u64::from(rhs.val));
+        MontgomeryElement32 { ctx: self.ctx, val }
+    }
+}
+
+impl<'a> MulAssign for MontgomeryElement32<'a> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Trait bound required of `T` to support [`MontgomeryElement`], identical
+/// to the bound [`Montgomery<T>`]'s own [`MontgomeryOperation`] impl needs;
+/// collected here once so it isn't repeated on every operator impl below.
+pub trait MontgomeryInt:
+    Clone
+    + Ord
+    + for<'x> BitAndAssign<&'x Self>
+    + for<'x> AddAssign<&'x Self>
+    + for<'x> SubAssign<&'x Self>
+    + for<'x> MulAssign<&'x Self>
+    + for<'x> BitAnd<&'x Self, Output = Self>
+    + for<'x> Sub<&'x Self, Output = Self>
+    + for<'x> Rem<&'x Self, Output = Self>
+    + Neg<Output = Self>
+    + Shl<u32, Output = Self>
+    + ShrAssign<u32>
+    + ShlAssign<u32>
+    + From<u8>
+{
+}
+impl<T> MontgomeryInt for T where
+    T: Clone
+        + Ord
+        + for<'x> BitAndAssign<&'x Self>
+        + for<'x> AddAssign<&'x Self>
+        + for<'x> SubAssign<&'x Self>
+        + for<'x> MulAssign<&'x Self>
+        + for<'x> BitAnd<&'x Self, Output = Self>
+        + for<'x> Sub<&'x Self, Output = Self>
+        + for<'x> Rem<&'x Self, Output = Self>
+        + Neg<Output = Self>
+        + Shl<u32, Output = Self>
+        + ShrAssign<u32>
+        + ShlAssign<u32>
+        + From<u8>
+{
+}
+
+/// A residue held in Montgomery form for the generic bignum [`Montgomery<T>`]
+///
+/// See [`MontgomeryElement64`] for the rationale.
+/// ```
+/// use modulo_n_tools::montgomery::{Montgomery, MontgomeryElement, MontgomeryOperation};
+/// let m = Montgomery::<i128>::new(97);
+/// let a = MontgomeryElement::from_int(&m, 60);
+/// let b = MontgomeryElement::from_int(&m, 70);
+/// assert_eq!((a.clone() + b.clone()).to_int(), 33);
+/// assert_eq!((a.clone() - b.clone()).to_int(), 87);
+/// assert_eq!((a * b).to_int(), 29);
+/// ```
+#[derive(Clone)]
+pub struct MontgomeryElement<'a, T: MontgomeryInt> {
+    ctx: &'a Montgomery<T>,
+    val: T,
+}
+
+impl<'a, T: MontgomeryInt> MontgomeryElement<'a, T> {
+    /// $`x \mapsto xR \bmod N`$
+    pub fn from_int(ctx: &'a Montgomery<T>, x: T) -> Self {
+        MontgomeryElement {
+            val: ctx.convert(x),
+            ctx,
+        }
+    }
+    /// $`xR \bmod N \mapsto x`$
+    pub fn to_int(&self) -> T {
+        self.ctx.reduction(self.val.clone())
+    }
+    /// $`x^p R \bmod N`$, staying in Montgomery form
+    pub fn pow<V>(&self, mut p: V) -> Self
+    where
+        V: Ord + ShrAssign<u8> + From<u8>,
+        for<'x> &'x V: BitAnd<Output = V>,
+    {
+        let c0 = V::from(0);
+        let c1 = V::from(1);
+        let mut x = self.clone();
+        let mut y = MontgomeryElement::from_int(self.ctx, T::from(1));
+        while p > c0 {
+            if &p & &c1 != c0 {
+                y *= x.clone();
+            }
+            x = x.clone() * x.clone();
+            p >>= 1;
+        }
+        y
+    }
+}
+
+impl<'a, T: MontgomeryInt> Add for MontgomeryElement<'a, T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement operands must share the same modulus"
+        );
+        let mut val = self.val;
+        val += &rhs.val;
+        if val >= self.ctx.n {
+            val -= &self.ctx.n;
+        }
+        MontgomeryElement { ctx: self.ctx, val }
+    }
+}
+
+impl<'a, T: MontgomeryInt> PartialEq for MontgomeryElement<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        assert!(
+            self.ctx.n == other.ctx.n,
+            "MontgomeryElement operands must share the same modulus"
+        );
+        self.val == other.val
+    }
+}
+
+impl<'a, T: MontgomeryInt> Sub for MontgomeryElement<'a, T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement operands must share the same modulus"
+        );
+        let mut val = self.val;
+        if val >= rhs.val {
+            val -= &rhs.val;
+        } else {
+            val += &self.ctx.n;
+            val -= &rhs.val;
+        }
+        MontgomeryElement { ctx: self.ctx, val }
+    }
+}
+
+impl<'a, T: MontgomeryInt> Neg for MontgomeryElement<'a, T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let zero = T::from(0);
+        let val = if self.val == zero {
+            zero
+        } else {
+            let mut n = self.ctx.n.clone();
+            n -= &self.val;
+            n
+        };
+        MontgomeryElement { ctx: self.ctx, val }
+    }
+}
+
+impl<'a, T: MontgomeryInt> Mul for MontgomeryElement<'a, T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        assert!(
+            self.ctx.n == rhs.ctx.n,
+            "MontgomeryElement operands must share the same modulus"
+        );
+        let mut val = self.val;
+        val *= &rhs.val;
+        let val = self.ctx.reduction(val);
+        MontgomeryElement { ctx: self.ctx, val }
+    }
+}
+
+impl<'a, T: MontgomeryInt> MulAssign for MontgomeryElement<'a, T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
@@ -0,0 +1,543 @@
+use crate::gcd;
+use crate::montgomery::{
+    Montgomery, Montgomery64, MontgomeryElement, MontgomeryInt, MontgomeryOperation,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Deterministic Miller–Rabin primality test for all `u64`
+///
+/// The fixed witness set `{2,3,5,7,11,13,17,19,23,29,31,37}` is known to be
+/// deterministic over the entire `u64` range.
+/// ```
+/// use modulo_n_tools::prime::is_prime_u64;
+/// assert!(is_prime_u64(2));
+/// assert!(is_prime_u64(97));
+/// assert!(!is_prime_u64(91));
+/// assert!(!is_prime_u64(1));
+/// ```
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in WITNESSES.iter() {
+        if n == p {
+            return true;
+        }
+        if n.is_multiple_of(p) {
+            return false;
+        }
+    }
+    let neg_one = n - 1;
+    let mut d = neg_one;
+    let mut s = 0u32;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    let m = Montgomery64::new(n);
+    let neg_one_mont = m.convert(neg_one);
+    'witness: for &a in WITNESSES.iter() {
+        let x = m.powmod(a, d);
+        if x == 1 || x == neg_one {
+            continue;
+        }
+        let mut mx = m.convert(x);
+        for _ in 1..s {
+            mx = m.reduction(u128::from(mx) * u128::from(mx));
+            if mx == neg_one_mont {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+fn gcd64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// splitmix64, used to seed Pollard's rho without pulling in an RNG dependency
+fn splitmix64(x: u64) -> u64 {
+    let x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let z = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    let z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// One Brent-batched run of Pollard's rho for `g(x) = x^2 + c mod n`, seeded
+/// at `x0`. Returns a nontrivial factor of `n`, or `None` if this `c`/`x0`
+/// combination collapsed onto the trivial factor `n` and should be retried.
+fn brent(n: u64, c: u64, x0: u64) -> Option<u64> {
+    const BATCH: u64 = 128;
+    let m = Montgomery64::new(n);
+    let c_mont = m.convert(c);
+    let g = |x: u64| -> u64 {
+        let sq = m.reduction(u128::from(x) * u128::from(x));
+        u64::try_from((u128::from(sq) + u128::from(c_mont)) % u128::from(n)).unwrap()
+    };
+    let mut y = m.convert(x0);
+    let mut x;
+    let mut ys;
+    let mut d = 1;
+    let mut r = 1;
+    'outer: while d == 1 {
+        x = y;
+        for _ in 0..r {
+            y = g(y);
+        }
+        let mut k = 0;
+        while k < r && d == 1 {
+            ys = y;
+            let batch = BATCH.min(r - k);
+            let mut q = 1u64;
+            for _ in 0..batch {
+                y = g(y);
+                let diff = x.abs_diff(y);
+                q = u64::try_from((u128::from(q) * u128::from(diff)) % u128::from(n)).unwrap();
+            }
+            d = gcd64(q, n);
+            k += batch;
+            if d == n {
+                // the batched gcd degenerated to n: walk the suspect batch
+                // one step at a time to isolate the actual factor
+                let mut z = ys;
+                d = 1;
+                while d == 1 {
+                    z = g(z);
+                    let diff = x.abs_diff(z);
+                    d = gcd64(diff, n);
+                    if z == y {
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        r *= 2;
+    }
+    if d <= 1 || d == n {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+fn pollard_rho(n: u64, seed: &mut u64) -> u64 {
+    loop {
+        *seed = splitmix64(*seed);
+        let c = 1 + *seed % (n - 1);
+        *seed = splitmix64(*seed);
+        let x0 = *seed % n;
+        if let Some(d) = brent(n, c, x0) {
+            return d;
+        }
+    }
+}
+
+fn factorize_odd(n: u64, seed: &mut u64, factors: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime_u64(n) {
+        factors.push(n);
+        return;
+    }
+    let d = pollard_rho(n, seed);
+    factorize_odd(d, seed, factors);
+    factorize_odd(n / d, seed, factors);
+}
+
+/// Sorted prime factorization of `n` (with multiplicity)
+///
+/// Peels out factors of 2, tests the odd cofactor for primality with
+/// [`is_prime_u64`], and otherwise splits it with the Brent variant of
+/// Pollard's rho (see [`brent`]), recursing on the factor and cofactor it
+/// finds.
+///
+/// `u64`-only; see [`factorize_generic`] for the bignum [`Montgomery<T>`] path.
+///
+/// `0` has no prime factorization, so `factorize(0)` returns an empty
+/// vector rather than looping forever peeling out factors of 2.
+/// ```
+/// use modulo_n_tools::prime::factorize;
+/// assert_eq!(factorize(0), Vec::<u64>::new());
+/// assert_eq!(factorize(1), Vec::<u64>::new());
+/// assert_eq!(factorize(97), vec![97]);
+/// assert_eq!(factorize(360), vec![2, 2, 2, 3, 3, 5]);
+/// ```
+pub fn factorize(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut factors = Vec::new();
+    let mut n = n;
+    while n.is_multiple_of(2) {
+        factors.push(2);
+        n /= 2;
+    }
+    let mut seed = n ^ 0x9E37_79B9_7F4A_7C15;
+    factorize_odd(n, &mut seed, &mut factors);
+    factors.sort_unstable();
+    factors
+}
+
+/// Smallest primitive root modulo prime `p`
+///
+/// Factors $`p - 1`$ with [`factorize`], then scans candidates
+/// $`g = 2, 3, \dots`$ for the first one with
+/// $`g^{(p-1)/q} \not\equiv 1 \pmod p`$ for every distinct prime factor `q`
+/// of `p - 1`, using [`Montgomery64::powmod`] for the exponentiations.
+///
+/// `p` is assumed prime (see [`is_prime_u64`]); behaviour is unspecified
+/// otherwise.
+/// ```
+/// use modulo_n_tools::prime::primitive_root;
+/// assert_eq!(primitive_root(7), 3);
+/// assert_eq!(primitive_root(97), 5);
+/// ```
+pub fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let p1 = p - 1;
+    let mut factors = factorize(p1);
+    factors.dedup();
+    let m = Montgomery64::new(p);
+    let mut g = 2u64;
+    loop {
+        if factors.iter().all(|&q| m.powmod(g, p1 / q) != 1) {
+            return g;
+        }
+        g += 1;
+    }
+}
+
+/// A generator of the order-`n` subgroup of $`(\mathbb{Z}/p\mathbb{Z})^*`$,
+/// the root needed to seed a radix-2 NTT
+///
+/// Requires $`n \mid p - 1`$; returns
+/// $`\text{primitive\_root}(p)^{(p-1)/n} \bmod p`$, or `None` when `n` does
+/// not divide `p - 1`.
+/// ```
+/// use modulo_n_tools::prime::nth_root_of_unity;
+/// assert_eq!(nth_root_of_unity(17, 8), Some(9));
+/// assert_eq!(nth_root_of_unity(17, 5), None);
+/// assert_eq!(nth_root_of_unity(2, 1), Some(1));
+/// ```
+pub fn nth_root_of_unity(p: u64, n: u64) -> Option<u64> {
+    let p1 = p - 1;
+    if n == 0 || !p1.is_multiple_of(n) {
+        return None;
+    }
+    if p == 2 {
+        // Montgomery reduction requires an odd modulus; only `n == 1`
+        // divides `p - 1 == 1`, and its root of unity is trivially `1`.
+        return Some(1);
+    }
+    let g = primitive_root(p);
+    let m = Montgomery64::new(p);
+    Some(m.powmod(g, p1 / n))
+}
+
+/// $`\text{base}^{\text{exp}} \bmod N`$ for the bignum [`Montgomery<T>`] path,
+/// returning an ordinary (non-Montgomery-form) integer
+///
+/// A square-and-multiply loop over [`MontgomeryElement`], halving the
+/// exponent with `Div`/`Rem` rather than bit-shifts since [`MontgomeryInt`]
+/// gives no byte-sized `Shr`.
+fn powmod_generic<T>(ctx: &Montgomery<T>, base: T, mut exp: T) -> T
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let two = T::from(2);
+    let mut x = MontgomeryElement::from_int(ctx, base);
+    let mut y = MontgomeryElement::from_int(ctx, T::from(1));
+    while exp > zero {
+        if (&exp % &two) != zero {
+            y *= x.clone();
+        }
+        x = x.clone() * x.clone();
+        exp = &exp / &two;
+    }
+    y.to_int()
+}
+
+const GENERIC_WITNESSES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Miller–Rabin probable-primality test for the bignum [`Montgomery<T>`] path
+///
+/// Unlike [`is_prime_u64`], the fixed witness set `{2,...,37}` is not known
+/// to be deterministic once `n` can exceed `u64::MAX`, so this only reports
+/// probable primality; it is never exposed as a public `is_prime`-style
+/// function.
+fn is_probably_prime_generic<T>(n: &T) -> bool
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let one = T::from(1);
+    let two = T::from(2);
+    if n <= &one {
+        return false;
+    }
+    for &p in GENERIC_WITNESSES.iter() {
+        let p = T::from(p);
+        if n == &p {
+            return true;
+        }
+        if (n % &p) == zero {
+            return false;
+        }
+    }
+    let neg_one = n - &one;
+    let mut d = neg_one.clone();
+    let mut s = 0u32;
+    while (&d % &two) == zero {
+        d = &d / &two;
+        s += 1;
+    }
+    let ctx = Montgomery::new(n.clone());
+    let neg_one_mont = MontgomeryElement::from_int(&ctx, neg_one.clone());
+    'witness: for &a in GENERIC_WITNESSES.iter() {
+        let a = T::from(a);
+        if &a >= n {
+            continue;
+        }
+        let x = powmod_generic(&ctx, a, d.clone());
+        if x == one || x == neg_one {
+            continue;
+        }
+        let mut mx = MontgomeryElement::from_int(&ctx, x);
+        for _ in 1..s {
+            mx = mx.clone() * mx.clone();
+            if mx == neg_one_mont {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// One Brent-batched run of Pollard's rho for `g(x) = x^2 + c mod n`, seeded
+/// at `x0`, generic over the bignum [`Montgomery<T>`] path. See [`brent`]
+/// for the rationale; `x`, `y` and `ys` are [`MontgomeryElement`]s, so the
+/// `g` iteration stays in Montgomery form, while each difference is converted
+/// back with `to_int` before it is folded into the batched product.
+fn brent_generic<T>(n: &T, c: T, x0: T) -> Option<T>
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    const BATCH: u64 = 128;
+    let one = T::from(1);
+    let ctx = Montgomery::new(n.clone());
+    let c_elem = MontgomeryElement::from_int(&ctx, c);
+    let mut y = MontgomeryElement::from_int(&ctx, x0);
+    let mut x;
+    let mut ys;
+    let mut d = one.clone();
+    let mut r = 1u64;
+    'outer: while d == one {
+        x = y.clone();
+        for _ in 0..r {
+            y = y.clone() * y.clone() + c_elem.clone();
+        }
+        let mut k = 0u64;
+        while k < r && d == one {
+            ys = y.clone();
+            let batch = BATCH.min(r - k);
+            let mut q = one.clone();
+            for _ in 0..batch {
+                y = y.clone() * y.clone() + c_elem.clone();
+                let diff = (x.clone() - y.clone()).to_int();
+                q = crate::mul_mod(&q, &diff, n);
+            }
+            d = gcd(q, n.clone());
+            k += batch;
+            if &d == n {
+                // the batched gcd degenerated to n: walk the suspect batch
+                // one step at a time to isolate the actual factor
+                let mut z = ys;
+                d = one.clone();
+                while d == one {
+                    z = z.clone() * z.clone() + c_elem.clone();
+                    let diff = (x.clone() - z.clone()).to_int();
+                    d = gcd(diff, n.clone());
+                    if z.to_int() == y.to_int() {
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        r *= 2;
+    }
+    if d <= one || &d == n {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+fn pollard_rho_generic<T>(n: &T) -> T
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let one = T::from(1);
+    let two = T::from(2);
+    let mut c = one.clone();
+    loop {
+        if let Some(d) = brent_generic(n, c.clone(), two.clone()) {
+            return d;
+        }
+        c = &c + &one;
+    }
+}
+
+fn factorize_odd_generic<T>(n: T, factors: &mut Vec<T>)
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let one = T::from(1);
+    if n == one {
+        return;
+    }
+    if is_probably_prime_generic(&n) {
+        factors.push(n);
+        return;
+    }
+    let d = pollard_rho_generic(&n);
+    let q = &n / &d;
+    factorize_odd_generic(d, factors);
+    factorize_odd_generic(q, factors);
+}
+
+/// Sorted prime factorization of `n` (with multiplicity), generic over the
+/// bignum [`Montgomery<T>`] path
+///
+/// The `u64`-hardcoded [`factorize`] stays deterministic via
+/// [`is_prime_u64`]'s certified witness set; here the recursion base case is
+/// [`is_probably_prime_generic`] (Miller–Rabin, probable rather than
+/// certified primality), and Pollard's rho is reseeded by incrementing `c`
+/// rather than a `u64`-only splitmix RNG.
+///
+/// Neither `0` nor negative `n` have a prime factorization, so both return
+/// an empty vector rather than looping forever peeling out factors of 2.
+/// ```
+/// use modulo_n_tools::prime::factorize_generic;
+/// assert_eq!(factorize_generic(0i128), Vec::<i128>::new());
+/// assert_eq!(factorize_generic(-6i128), Vec::<i128>::new());
+/// assert_eq!(factorize_generic(1i128), Vec::<i128>::new());
+/// assert_eq!(factorize_generic(97i128), vec![97]);
+/// assert_eq!(factorize_generic(360i128), vec![2, 2, 2, 3, 3, 5]);
+/// ```
+pub fn factorize_generic<T>(n: T) -> Vec<T>
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let two = T::from(2);
+    if n <= zero {
+        return Vec::new();
+    }
+    let mut factors = Vec::new();
+    let mut n = n;
+    while (&n % &two) == zero {
+        factors.push(two.clone());
+        n = &n / &two;
+    }
+    factorize_odd_generic(n, &mut factors);
+    factors.sort_unstable();
+    factors
+}
+
+/// Smallest primitive root modulo prime `p`, generic over the bignum
+/// [`Montgomery<T>`] path
+///
+/// See [`primitive_root`] for the algorithm; `p` is assumed prime (see
+/// [`is_probably_prime_generic`]).
+/// ```
+/// use modulo_n_tools::prime::primitive_root_generic;
+/// assert_eq!(primitive_root_generic(7i128), 3);
+/// assert_eq!(primitive_root_generic(97i128), 5);
+/// ```
+pub fn primitive_root_generic<T>(p: T) -> T
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let one = T::from(1);
+    let two = T::from(2);
+    if p == two {
+        return one;
+    }
+    let p1 = &p - &one;
+    let mut factors = factorize_generic(p1.clone());
+    factors.dedup();
+    let ctx = Montgomery::new(p.clone());
+    let mut g = two;
+    loop {
+        if factors
+            .iter()
+            .all(|q| powmod_generic(&ctx, g.clone(), &p1 / q) != one)
+        {
+            return g;
+        }
+        g = &g + &one;
+    }
+}
+
+/// A generator of the order-`n` subgroup of $`(\mathbb{Z}/p\mathbb{Z})^*`$,
+/// generic over the bignum [`Montgomery<T>`] path
+///
+/// See [`nth_root_of_unity`] for the algorithm.
+/// ```
+/// use modulo_n_tools::prime::nth_root_of_unity_generic;
+/// assert_eq!(nth_root_of_unity_generic(17i128, 8i128), Some(9));
+/// assert_eq!(nth_root_of_unity_generic(17i128, 5i128), None);
+/// assert_eq!(nth_root_of_unity_generic(2i128, 1i128), Some(1));
+/// ```
+pub fn nth_root_of_unity_generic<T>(p: T, n: T) -> Option<T>
+where
+    T: MontgomeryInt,
+    for<'x> &'x T:
+        Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let zero = T::from(0);
+    let one = T::from(1);
+    let two = T::from(2);
+    let p1 = &p - &one;
+    if n == zero || (&p1 % &n) != zero {
+        return None;
+    }
+    if p == two {
+        // Montgomery reduction requires an odd modulus; only `n == 1`
+        // divides `p - 1 == 1`, and its root of unity is trivially `1`.
+        return Some(one);
+    }
+    let g = primitive_root_generic(p.clone());
+    let ctx = Montgomery::new(p);
+    Some(powmod_generic(&ctx, g, &p1 / &n))
+}
@@ -1,9 +1,13 @@
 use modulo_n_tools::montgomery::*;
+use modulo_n_tools::prime::{
+    factorize, factorize_generic, is_prime_u64, nth_root_of_unity, nth_root_of_unity_generic,
+    primitive_root, primitive_root_generic,
+};
 use modulo_n_tools::*;
 use num::BigInt;
 use rand::Rng;
 use rug::Integer;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use test_case::test_case;
 
 #[test_case(100; "small")]
@@ -45,6 +49,131 @@ fn mul_mod_test(n: usize) {
     }
 }
 
+fn gcd_i64(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[test]
+fn inv_mod_test_small() {
+    assert_eq!(inv_mod(3, 5), Some(2));
+    assert_eq!(inv_mod(2, 4), None);
+    assert_eq!(inv_mod(3u64, 5u64), Some(2));
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn inv_mod_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m = i64::from(rng.gen::<u16>()) + 2;
+        let a = rng.gen::<i64>().abs() % m;
+        match inv_mod(a, m) {
+            Some(x) => assert_eq!(i128::from(a) * i128::from(x) % i128::from(m), 1),
+            None => assert_ne!(gcd_i64(a, m), 1),
+        }
+    }
+}
+
+#[test]
+fn inv_mod_prime_test_small() {
+    assert_eq!(inv_mod_prime(3, &5), 2);
+    assert_eq!(inv_mod_prime(3u64, &5u64), 2);
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn inv_mod_prime_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m = loop {
+            let m = i64::from(rng.gen::<u16>()) + 2;
+            if is_prime(m) {
+                break m;
+            }
+        };
+        let a = 1 + rng.gen::<i64>().abs() % (m - 1);
+        assert_eq!(inv_mod_prime(a, &m), inv_mod(a, m).unwrap());
+    }
+}
+
+#[test]
+fn div_mod_test_small() {
+    assert_eq!(div_mod(&4, &3, &5), Some(3));
+    assert_eq!(div_mod(&4u64, &3u64, &5u64), Some(3));
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn div_mod_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m = i64::from(rng.gen::<u16>()) + 2;
+        let a = rng.gen::<i64>().abs() % m;
+        let b = rng.gen::<i64>().abs() % m;
+        match div_mod(&a, &b, &m) {
+            Some(x) => assert_eq!(
+                i128::from(b) * i128::from(x) % i128::from(m),
+                i128::from(a) % i128::from(m)
+            ),
+            None => assert_ne!(gcd_i64(b, m), 1),
+        }
+    }
+}
+
+#[test]
+fn crt_test_small() {
+    assert_eq!(crt(&2, &3, &3, &5), Some((8, 15)));
+    assert_eq!(crt(&0, &4, &1, &6), None);
+    assert_eq!(crt(&3u64, &5u64, &1u64, &4u64), Some((13, 20)));
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn crt_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m1 = i64::from(rng.gen::<u16>()) + 1;
+        let m2 = i64::from(rng.gen::<u16>()) + 1;
+        let r1 = rng.gen::<i64>().abs() % m1;
+        let r2 = rng.gen::<i64>().abs() % m2;
+        let g = gcd_i64(m1, m2);
+        match crt(&r1, &m1, &r2, &m2) {
+            Some((r, lcm)) => {
+                assert_eq!(lcm, m1 / g * m2);
+                assert_eq!(r % m1, r1);
+                assert_eq!(r % m2, r2);
+            }
+            None => assert_ne!((r2 - r1) % g, 0),
+        }
+    }
+}
+
+#[test_case(100; "small")]
+#[test_case(1000; "medium")]
+fn crt_many_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let mut ms: Vec<i64> = Vec::new();
+        while ms.len() < 4 {
+            let m = i64::from(rng.gen::<u8>()) + 2;
+            if is_prime(m) && !ms.contains(&m) {
+                ms.push(m);
+            }
+        }
+        let rs: Vec<i64> = ms.iter().map(|&m| rng.gen::<i64>().abs() % m).collect();
+        let (r, lcm) = crt_many(&rs, &ms).unwrap();
+        assert_eq!(lcm, ms.iter().product());
+        for (&r_i, &m_i) in rs.iter().zip(ms.iter()) {
+            assert_eq!(r % m_i, r_i);
+        }
+    }
+}
+
 fn is_prime(n: i64) -> bool {
     if n == 2 {
         return true;
@@ -144,6 +273,262 @@ fn montgomery64_test(n: usize) {
     }
 }
 
+#[test]
+fn montgomery_element64_test_small() {
+    let m = Montgomery64::new(97);
+    let a = MontgomeryElement64::from_int(&m, 60);
+    let b = MontgomeryElement64::from_int(&m, 70);
+    assert_eq!((a + b).to_int(), 33);
+    assert_eq!((a - b).to_int(), 87);
+    assert_eq!((a * b).to_int(), 29);
+    assert_eq!((-a).to_int(), 37);
+    assert_eq!(a.pow(77u64).to_int(), 58);
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn montgomery_element64_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m = loop {
+            let m = u64::from(rng.gen::<u32>()) + 3;
+            if is_prime_u64(m) {
+                break m;
+            }
+        };
+        let mon = Montgomery64::new(m);
+        let x = rng.gen::<u64>() % m;
+        let y = rng.gen::<u64>() % m;
+        let a = MontgomeryElement64::from_int(&mon, x);
+        let b = MontgomeryElement64::from_int(&mon, y);
+        assert_eq!((a + b).to_int(), (x + y) % m);
+        assert_eq!(
+            (a * b).to_int(),
+            u64::try_from(u128::from(x) * u128::from(y) % u128::from(m)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn montgomery_element32_test_small() {
+    let m = Montgomery32::new(89);
+    let a = MontgomeryElement32::from_int(&m, 40);
+    let b = MontgomeryElement32::from_int(&m, 60);
+    assert_eq!((a + b).to_int(), 11);
+    assert_eq!((a - b).to_int(), 69);
+    assert_eq!((a * b).to_int(), 86);
+    assert_eq!(a.pow(57u64).to_int(), 68);
+}
+
+#[test]
+fn montgomery_element_generic_test_small() {
+    let m = Montgomery::<i128>::new(97);
+    let a = MontgomeryElement::from_int(&m, 60);
+    let b = MontgomeryElement::from_int(&m, 70);
+    assert_eq!((a.clone() + b.clone()).to_int(), 33);
+    assert_eq!((a.clone() - b.clone()).to_int(), 87);
+    assert_eq!((a.clone() * b).to_int(), 29);
+    assert_eq!((-a.clone()).to_int(), 37);
+    assert_eq!(a.pow(77u64).to_int(), 58);
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn montgomery_element_generic_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m = loop {
+            let m = i128::from(rng.gen::<u32>()) + 3;
+            if is_prime_u64(m.try_into().unwrap()) {
+                break m;
+            }
+        };
+        let mon = Montgomery::<i128>::new(m);
+        let x = i128::from(rng.gen::<u32>()) % m;
+        let y = i128::from(rng.gen::<u32>()) % m;
+        let a = MontgomeryElement::from_int(&mon, x);
+        let b = MontgomeryElement::from_int(&mon, y);
+        assert_eq!((a.clone() + b.clone()).to_int(), (x + y) % m);
+        assert_eq!((a * b).to_int(), (x * y) % m);
+    }
+}
+
+#[test]
+fn is_prime_u64_test_small() {
+    assert!(is_prime_u64(2));
+    assert!(is_prime_u64(3));
+    assert!(!is_prime_u64(0));
+    assert!(!is_prime_u64(1));
+    assert!(!is_prime_u64(91));
+    assert!(is_prime_u64(2u64.pow(61) - 1));
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+#[test_case(100000; "big")]
+fn is_prime_u64_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let x = u64::from(rng.gen::<u32>());
+        assert_eq!(is_prime_u64(x), is_prime(x.try_into().unwrap()));
+    }
+}
+
+#[test]
+fn factorize_test_small() {
+    assert_eq!(factorize(0), Vec::<u64>::new());
+    assert_eq!(factorize(1), Vec::<u64>::new());
+    assert_eq!(factorize(97), vec![97]);
+    assert_eq!(factorize(360), vec![2, 2, 2, 3, 3, 5]);
+}
+
+#[test_case(100; "small")]
+#[test_case(1000; "medium")]
+fn factorize_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let x = u64::from(rng.gen::<u32>()) + 2;
+        let factors = factorize(x);
+        assert_eq!(factors.iter().product::<u64>(), x);
+        assert!(factors.iter().all(|&p| is_prime_u64(p)));
+    }
+}
+
+#[test]
+fn batch_inv_mod_test_small() {
+    let mut xs = [2, 3, 4];
+    assert_eq!(batch_inv_mod(&mut xs, &7), Ok(()));
+    assert_eq!(xs, [4, 5, 2]);
+    assert_eq!(batch_inv_mod(&mut [1, 0, 2], &7), Err(vec![1]));
+}
+
+#[test]
+fn batch_inv_mod_test_small_u64() {
+    let mut xs: [u64; 3] = [2, 3, 4];
+    assert_eq!(batch_inv_mod(&mut xs, &7u64), Ok(()));
+    assert_eq!(xs, [4, 5, 2]);
+    assert_eq!(batch_inv_mod(&mut [1u64, 0, 2], &7u64), Err(vec![1]));
+}
+
+#[test_case(100; "small")]
+#[test_case(10000; "medium")]
+fn batch_inv_mod_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let m = loop {
+            let m = i64::from(rng.gen::<u16>()) + 2;
+            if is_prime_u64(m.try_into().unwrap()) {
+                break m;
+            }
+        };
+        let mut xs: Vec<i64> = (0..8)
+            .map(|_| 1 + rng.gen::<i64>().abs() % (m - 1))
+            .collect();
+        let expected: Vec<i64> = xs.iter().map(|x| inv_mod(*x, m).unwrap()).collect();
+        batch_inv_mod(&mut xs, &m).unwrap();
+        assert_eq!(xs, expected);
+    }
+}
+
+#[test]
+fn primitive_root_test_small() {
+    assert_eq!(primitive_root(7), 3);
+    assert_eq!(primitive_root(97), 5);
+}
+
+#[test_case(100; "small")]
+#[test_case(1000; "medium")]
+fn primitive_root_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let p = loop {
+            let p = u64::from(rng.gen::<u16>()) + 3;
+            if is_prime_u64(p) {
+                break p;
+            }
+        };
+        let g = primitive_root(p);
+        let p1 = p - 1;
+        let factors = factorize(p1);
+        let m = Montgomery64::new(p);
+        assert_eq!(m.powmod(g, p1), 1);
+        for q in factors {
+            assert_ne!(m.powmod(g, p1 / q), 1);
+        }
+    }
+}
+
+#[test]
+fn nth_root_of_unity_test() {
+    assert_eq!(nth_root_of_unity(17, 8), Some(9));
+    assert_eq!(nth_root_of_unity(17, 5), None);
+    assert_eq!(nth_root_of_unity(2, 1), Some(1));
+    let m = Montgomery64::new(17);
+    let r = nth_root_of_unity(17, 8).unwrap();
+    assert_eq!(m.powmod(r, 8), 1);
+    assert_ne!(m.powmod(r, 4), 1);
+}
+
+#[test]
+fn factorize_generic_test_small() {
+    assert_eq!(factorize_generic(0i128), Vec::<i128>::new());
+    assert_eq!(factorize_generic(-6i128), Vec::<i128>::new());
+    assert_eq!(factorize_generic(1i128), Vec::<i128>::new());
+    assert_eq!(factorize_generic(97i128), vec![97]);
+    assert_eq!(factorize_generic(360i128), vec![2, 2, 2, 3, 3, 5]);
+}
+
+#[test_case(100; "small")]
+#[test_case(1000; "medium")]
+fn factorize_generic_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let x = i128::from(rng.gen::<u32>()) + 2;
+        let factors = factorize_generic(x);
+        assert_eq!(factors.iter().product::<i128>(), x);
+        assert!(factors.iter().all(|&p| is_prime_u64(p.try_into().unwrap())));
+    }
+}
+
+#[test]
+fn primitive_root_generic_test_small() {
+    assert_eq!(primitive_root_generic(7i128), 3);
+    assert_eq!(primitive_root_generic(97i128), 5);
+}
+
+#[test_case(100; "small")]
+#[test_case(1000; "medium")]
+fn primitive_root_generic_test(n: usize) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..n {
+        let p = loop {
+            let p = i128::from(rng.gen::<u16>()) + 3;
+            if is_prime_u64(p.try_into().unwrap()) {
+                break p;
+            }
+        };
+        let g = primitive_root_generic(p);
+        let p1 = p - 1;
+        let factors = factorize_generic(p1);
+        let m = Montgomery::new(p);
+        assert_eq!(m.powmod(g, p1), 1);
+        for q in factors {
+            assert_ne!(m.powmod(g, p1 / q), 1);
+        }
+    }
+}
+
+#[test]
+fn nth_root_of_unity_generic_test() {
+    assert_eq!(nth_root_of_unity_generic(17i128, 8i128), Some(9));
+    assert_eq!(nth_root_of_unity_generic(17i128, 5i128), None);
+    assert_eq!(nth_root_of_unity_generic(2i128, 1i128), Some(1));
+    let m = Montgomery::new(17i128);
+    let r = nth_root_of_unity_generic(17i128, 8i128).unwrap();
+    assert_eq!(m.powmod(r, 8), 1);
+    assert_ne!(m.powmod(r, 4), 1);
+}
+
 fn montgomery_num() {
     let m: BigInt = (BigInt::from(1) << 107) - 1;
     let mon = Montgomery::new(m.clone());